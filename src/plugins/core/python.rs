@@ -20,7 +20,8 @@ use crate::{cmd, env, file};
 #[derive(Debug)]
 pub struct PythonPlugin {
     core: CorePlugin,
-    precompiled_cache: CacheManager<Vec<(String, String, String)>>,
+    precompiled_cache: CacheManager<Vec<(String, String, String, String)>>,
+    pypy_cache: CacheManager<Vec<(String, String)>>,
 }
 
 impl PythonPlugin {
@@ -29,6 +30,8 @@ impl PythonPlugin {
         Self {
             precompiled_cache: CacheManager::new(core.cache_path.join("precompiled.msgpack.z"))
                 .with_fresh_duration(*env::MISE_FETCH_REMOTE_VERSIONS_CACHE),
+            pypy_cache: CacheManager::new(core.cache_path.join("pypy.msgpack.z"))
+                .with_fresh_duration(*env::MISE_FETCH_REMOTE_VERSIONS_CACHE),
             core,
         }
     }
@@ -71,20 +74,47 @@ impl PythonPlugin {
 
     fn fetch_remote_versions(&self) -> Result<Vec<String>> {
         let settings = Settings::get();
+        // only hit downloads.python.org for users who've opted into experimental features;
+        // everyone else keeps the previous behavior of listing only cpython versions (mise's
+        // hosted version list, falling back to python-build's definitions)
+        let pypy_versions = if settings.experimental {
+            match self.fetch_pypy_remote_versions() {
+                Ok(versions) => versions.iter().map(|(v, _)| v.to_string()).collect_vec(),
+                Err(e) => {
+                    warn!("failed to fetch pypy remote versions: {}", e);
+                    vec![]
+                }
+            }
+        } else {
+            vec![]
+        }
+        .into_iter();
         if self.should_install_precompiled(&settings) {
             let v = self
                 .fetch_precompiled_remote_versions()?
                 .iter()
-                .map(|(v, _, _)| v.to_string())
+                .map(|(v, _, _, _)| v.to_string())
+                .chain(pypy_versions)
                 .unique()
                 .collect();
             return Ok(v);
         }
-        match self.core.fetch_remote_versions_from_mise() {
-            Ok(Some(versions)) => return Ok(versions),
-            Ok(None) => {}
-            Err(e) => warn!("failed to fetch remote versions: {}", e),
-        }
+        let cpython_versions = match self.core.fetch_remote_versions_from_mise() {
+            Ok(Some(versions)) => versions,
+            Ok(None) => self.fetch_remote_versions_from_python_build()?,
+            Err(e) => {
+                warn!("failed to fetch remote versions: {}", e);
+                self.fetch_remote_versions_from_python_build()?
+            }
+        };
+        Ok(cpython_versions
+            .into_iter()
+            .chain(pypy_versions)
+            .unique()
+            .collect())
+    }
+
+    fn fetch_remote_versions_from_python_build(&self) -> Result<Vec<String>> {
         self.install_or_update_python_build()?;
         let python_build_bin = self.python_build_bin();
         CorePlugin::run_fetch_task_with_timeout(move || {
@@ -100,7 +130,99 @@ impl PythonPlugin {
         })
     }
 
+    /// fetches the list of available PyPy releases that have a precompiled tarball
+    /// matching this host's platform, e.g. `pypy3.10-7.3.16`
+    fn fetch_pypy_remote_versions(&self) -> Result<&Vec<(String, String)>> {
+        self.pypy_cache.get_or_try_init(|| {
+            let raw = HTTP_FETCH.get_text("https://downloads.python.org/pypy/versions.json")?;
+            let releases: Vec<PyPyRelease> = serde_json::from_str(&raw).into_diagnostic()?;
+            let platform = pypy_platform();
+            let versions = releases
+                .into_iter()
+                .filter(|r| r.python_version.starts_with('3') || r.python_version.starts_with('2'))
+                .flat_map(|r| {
+                    r.files
+                        .into_iter()
+                        .find(|f| f.platform == platform && f.filename.ends_with(".tar.bz2"))
+                        .map(|f| {
+                            (
+                                format!("pypy{}-{}", r.python_version, r.version),
+                                f.filename,
+                            )
+                        })
+                })
+                .collect_vec();
+            Ok(versions)
+        })
+    }
+
+    fn is_pypy(&self, version: &str) -> bool {
+        version.starts_with("pypy")
+    }
+
+    fn install_pypy(&self, ctx: &InstallContext) -> Result<()> {
+        let config = Config::get();
+        let version = &ctx.tv.version;
+        let pypy_versions = self.fetch_pypy_remote_versions()?;
+        // `pypy3.10` (major.minor only) resolves to the newest matching release; `pypy-7.3.16`
+        // resolves by exact pypy release version; the full `pypy3.10-7.3.16` matches exactly
+        let pypy_info = pypy_versions
+            .iter()
+            .find(|(v, _)| v == version)
+            .or_else(|| {
+                pypy_versions
+                    .iter()
+                    .find(|(v, _)| version == &format!("pypy-{}", v.rsplit_once('-').unwrap().1))
+            })
+            .or_else(|| {
+                pypy_versions
+                    .iter()
+                    .rev()
+                    .find(|(v, _)| v.rsplit_once('-').map(|(mm, _)| mm) == Some(version.as_str()))
+            });
+        let filename = match pypy_info {
+            Some((_, filename)) => filename,
+            None => bail!("no precompiled pypy version found for {version}"),
+        };
+        let url = format!("https://downloads.python.org/pypy/{filename}");
+        let install = ctx.tv.install_path();
+        let download = ctx.tv.download_path();
+        let tarball_path = download.join(filename);
+
+        ctx.pr.set_message(format!("downloading {}", &url));
+        HTTP.download_file(&url, &tarball_path)?;
+
+        ctx.pr
+            .set_message(format!("installing {}", tarball_path.display()));
+        // PyPy ships `.tar.bz2` archives, unlike the `.tar.gz` archives python-build-standalone
+        // publishes, so decompress the bzip2 layer ourselves before handing a plain tarball to
+        // `file::untar` rather than assuming it supports every compression format
+        let untar_path = download.join(filename.trim_end_matches(".bz2"));
+        {
+            let mut decoder = bzip2::read::BzDecoder::new(
+                std::fs::File::open(&tarball_path).into_diagnostic()?,
+            );
+            let mut out = std::fs::File::create(&untar_path).into_diagnostic()?;
+            std::io::copy(&mut decoder, &mut out).into_diagnostic()?;
+        }
+        file::untar(&untar_path, &download)?;
+        file::remove_all(&install)?;
+        let untarred = file::dir_entries(&download)?
+            .into_iter()
+            .find(|p| p.is_dir())
+            .ok_or_else(|| miette!("no directory found in pypy tarball"))?;
+        file::rename(untarred, &install)?;
+        file::make_symlink(&install.join("bin/pypy"), &install.join("bin/python"))?;
+
+        self.test_python(&config, &ctx.tv, ctx.pr.as_ref())?;
+
+        Ok(())
+    }
+
     fn python_path(&self, tv: &ToolVersion) -> PathBuf {
+        if self.is_pypy(&tv.version) {
+            return tv.install_short_path().join("bin/pypy");
+        }
         tv.install_short_path().join("bin/python")
     }
 
@@ -108,7 +230,7 @@ impl PythonPlugin {
         !settings.all_compile && !settings.python_compile && settings.experimental
     }
 
-    fn fetch_precompiled_remote_versions(&self) -> Result<&Vec<(String, String, String)>> {
+    fn fetch_precompiled_remote_versions(&self) -> Result<&Vec<(String, String, String, String)>> {
         self.precompiled_cache.get_or_try_init(|| {
             let raw = HTTP_FETCH.get_text("http://mise-versions.jdx.dev/python-precompiled")?;
             let versions = raw
@@ -121,6 +243,7 @@ impl PythonPlugin {
                             (
                                 caps[1].to_string(),
                                 caps[2].to_string(),
+                                precompiled_flavor(v).to_string(),
                                 caps[0].to_string(),
                             )
                         })
@@ -130,20 +253,47 @@ impl PythonPlugin {
         })
     }
 
+    /// which flavor of precompiled build to install when more than one is available for a
+    /// version, e.g. `python@3.13{flavor='freethreaded'}`. Defaults to `install_only` (the
+    /// slim build mise has always installed) so existing configs keep working unchanged.
+    fn precompiled_flavor(&self, tv: &ToolVersion, settings: &Settings) -> String {
+        tv.opts
+            .get("flavor")
+            .cloned()
+            .or_else(|| settings.python_precompiled_flavor.clone())
+            .unwrap_or_else(|| "install_only".to_string())
+    }
+
     fn install_precompiled(&self, ctx: &InstallContext) -> Result<()> {
         warn!("installing precompiled python from indygreg/python-build-standalone");
         warn!("if you experience issues with this python, switch to python-build");
         warn!("by running: mise settings set python_compile 0");
 
         let config = Config::get();
-        let precompile_info = self
+        let settings = Settings::try_get()?;
+        let flavor = self.precompiled_flavor(&ctx.tv, &settings);
+        let candidates = self
             .fetch_precompiled_remote_versions()?
             .iter()
             .rev()
-            .find(|(v, _, _)| &ctx.tv.version == v);
-        let (tag, filename) = match precompile_info {
-            Some((_, tag, filename)) => (tag, filename),
-            None => bail!("no precompiled version found for {}", ctx.tv),
+            .filter(|(v, ..)| &ctx.tv.version == v)
+            .collect_vec();
+        let (tag, filename) = match candidates.iter().find(|(_, _, f, _)| f == &flavor) {
+            Some((_, tag, _, filename)) => (tag, filename),
+            None if candidates.is_empty() => bail!(
+                "no precompiled version found for {} matching {}-{}\n\
+                if this is unexpected, verify the detected libc is correct for this host",
+                ctx.tv,
+                arch(),
+                os()
+            ),
+            None => {
+                let available = candidates.iter().map(|(_, _, f, _)| f.as_str()).join(", ");
+                bail!(
+                    "no precompiled build with flavor `{flavor}` found for {}\navailable flavors: {available}",
+                    ctx.tv
+                )
+            }
         };
         let url = format!(
             "https://github.com/indygreg/python-build-standalone/releases/download/{tag}/{filename}"
@@ -178,6 +328,23 @@ impl PythonPlugin {
             return Ok(());
         }
         pr.set_message("installing default packages".into());
+        let settings = Settings::try_get()?;
+        if let Some(uv) = self.uv_path(&settings) {
+            let result = CmdLineRunner::new(uv)
+                .with_pr(pr)
+                .arg("pip")
+                .arg("install")
+                .arg("-r")
+                .arg(&*env::MISE_PYTHON_DEFAULT_PACKAGES_FILE)
+                .arg("--python")
+                .arg(tv.install_path().join("bin/python"))
+                .envs(&config.env)
+                .execute();
+            if result.is_ok() {
+                return result;
+            }
+            warn!("uv pip install failed, falling back to pip");
+        }
         CmdLineRunner::new(tv.install_path().join("bin/python"))
             .with_pr(pr)
             .arg("-m")
@@ -190,6 +357,15 @@ impl PythonPlugin {
             .execute()
     }
 
+    /// returns the path to a usable `uv` binary if the `python_uv` setting is enabled
+    /// and `uv` can be resolved on PATH
+    fn uv_path(&self, settings: &Settings) -> Option<PathBuf> {
+        if !settings.python_uv {
+            return None;
+        }
+        file::which("uv")
+    }
+
     fn get_virtualenv(
         &self,
         config: &Config,
@@ -214,15 +390,39 @@ impl PythonPlugin {
             if !virtualenv.exists() {
                 if settings.python_venv_auto_create {
                     info!("setting up virtualenv at: {}", virtualenv.display());
-                    let mut cmd = CmdLineRunner::new(self.python_path(tv))
-                        .arg("-m")
-                        .arg("venv")
-                        .arg(&virtualenv)
-                        .envs(&config.env);
+                    let mut cmd = if let Some(uv) = self.uv_path(&settings) {
+                        CmdLineRunner::new(uv)
+                            .arg("venv")
+                            .arg(&virtualenv)
+                            .arg("--python")
+                            .arg(self.python_path(tv))
+                            .envs(&config.env)
+                    } else {
+                        CmdLineRunner::new(self.python_path(tv))
+                            .arg("-m")
+                            .arg("venv")
+                            .arg(&virtualenv)
+                            .envs(&config.env)
+                    };
                     if let Some(pr) = pr {
                         cmd = cmd.with_pr(pr);
                     }
-                    cmd.execute()?;
+                    if let Err(e) = cmd.execute() {
+                        if self.uv_path(&settings).is_some() {
+                            warn!("uv venv failed, falling back to `python -m venv`: {e}");
+                            let mut cmd = CmdLineRunner::new(self.python_path(tv))
+                                .arg("-m")
+                                .arg("venv")
+                                .arg(&virtualenv)
+                                .envs(&config.env);
+                            if let Some(pr) = pr {
+                                cmd = cmd.with_pr(pr);
+                            }
+                            cmd.execute()?;
+                        } else {
+                            return Err(e);
+                        }
+                    }
                 } else {
                     warn!(
                         "no venv found at: {p}\n\n\
@@ -279,12 +479,35 @@ impl Plugin for PythonPlugin {
     }
 
     fn legacy_filenames(&self) -> Result<Vec<String>> {
+        // mise's legacy-file resolver already walks up parent directories looking for this
+        // filename (the same way it does for every other plugin), so the only python-specific
+        // work here is parsing the contents below
         Ok(vec![".python-version".to_string()])
     }
 
+    /// pyenv/uv-style `.python-version` files may list more than one version, one per line,
+    /// with blank lines and `#` comments ignored. The first line is the primary interpreter;
+    /// mise installs and PATHs the rest, so tools like tox/nox that expect several interpreters
+    /// to be available keep working.
+    fn parse_legacy_file(&self, path: &Path) -> Result<String> {
+        let contents = file::read_to_string(path)?;
+        let versions = contents
+            .lines()
+            .map(|l| l.trim())
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .collect_vec();
+        if versions.is_empty() {
+            bail!("no version found in {}", path.display());
+        }
+        Ok(versions.join(" "))
+    }
+
     fn install_version_impl(&self, ctx: &InstallContext) -> Result<()> {
         let config = Config::get();
         let settings = Settings::try_get()?;
+        if self.is_pypy(&ctx.tv.version) {
+            return self.install_pypy(ctx);
+        }
         if self.should_install_precompiled(&settings) {
             return self.install_precompiled(ctx);
         }
@@ -347,11 +570,27 @@ impl Plugin for PythonPlugin {
     }
 }
 
+/// classifies a `python-precompiled` index line by the build flavor encoded in its filename,
+/// e.g. `cpython-3.13.0+20241008-x86_64_v3-unknown-linux-gnu-freethreaded+pgo-full.tar.gz`
+fn precompiled_flavor(line: &str) -> &'static str {
+    if line.contains("freethreaded") {
+        "freethreaded"
+    } else if line.contains("-debug") {
+        "debug"
+    } else if line.contains("install_only") {
+        "install_only"
+    } else {
+        "full"
+    }
+}
+
 fn os() -> &'static str {
-    if cfg!(target_env = "musl") {
-        "unknown-linux-musl"
-    } else if cfg!(target_os = "linux") {
-        "unknown-linux-gnu"
+    if cfg!(target_os = "linux") {
+        if is_musl_libc() {
+            "unknown-linux-musl"
+        } else {
+            "unknown-linux-gnu"
+        }
     } else if cfg!(target_os = "macos") {
         "apple-darwin"
     } else {
@@ -359,12 +598,111 @@ fn os() -> &'static str {
     }
 }
 
-fn arch() -> &'static str {
+/// detects whether the host's libc is musl rather than glibc, mirroring the probing
+/// packaging's `_manylinux`/`_musllinux` does: this reflects the _host_ mise is running
+/// on, not the libc mise itself was compiled against (`cfg!(target_env = "musl")`), since
+/// a gnu-built mise can still run on an Alpine host via e.g. a statically linked binary
+fn is_musl_libc() -> bool {
+    static MUSL: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *MUSL.get_or_init(|| {
+        let has_musl_loader = std::fs::read_dir("/lib")
+            .map(|entries| {
+                entries.flatten().any(|e| {
+                    e.file_name()
+                        .to_str()
+                        .is_some_and(|n| n.starts_with("ld-musl-"))
+                })
+            })
+            .unwrap_or(false);
+        if has_musl_loader {
+            return true;
+        }
+        cmd!("ldd", "--version")
+            .read()
+            .map(|out| out.to_lowercase().contains("musl"))
+            .unwrap_or(false)
+    })
+}
+
+fn arch() -> String {
     if cfg!(target_arch = "x86_64") {
-        "x86_64_v3" // TODO: make the version configurable
+        let settings = Settings::get();
+        if let Some(arch) = settings.python_precompiled_arch.clone() {
+            return arch;
+        }
+        x86_64_microarch_level().to_string()
     } else if cfg!(target_arch = "aarch64") {
-        "aarch64"
+        "aarch64".to_string()
     } else {
         panic!("unsupported arch")
     }
 }
+
+/// detects the highest x86_64 microarchitecture level
+/// (<https://en.wikipedia.org/wiki/X86-64#Microarchitecture_levels>) this CPU supports so we
+/// don't try to run AVX2/AVX-512 builds on older hardware
+#[cfg(target_arch = "x86_64")]
+fn x86_64_microarch_level() -> &'static str {
+    if std::arch::is_x86_feature_detected!("avx512f")
+        && std::arch::is_x86_feature_detected!("avx512bw")
+        && std::arch::is_x86_feature_detected!("avx512cd")
+        && std::arch::is_x86_feature_detected!("avx512dq")
+        && std::arch::is_x86_feature_detected!("avx512vl")
+    {
+        "x86_64_v4"
+    } else if std::arch::is_x86_feature_detected!("avx2")
+        && std::arch::is_x86_feature_detected!("bmi2")
+        && std::arch::is_x86_feature_detected!("fma")
+        && std::arch::is_x86_feature_detected!("movbe")
+        // `is_x86_feature_detected!` has no "osxsave" token (the spec's actual v3 requirement);
+        // "xsave" is the closest detectable proxy and is present on the same hardware in practice
+        && std::arch::is_x86_feature_detected!("xsave")
+    {
+        "x86_64_v3"
+    } else if std::arch::is_x86_feature_detected!("sse4.2")
+        && std::arch::is_x86_feature_detected!("popcnt")
+        && std::arch::is_x86_feature_detected!("cmpxchg16b")
+    {
+        "x86_64_v2"
+    } else {
+        "x86_64"
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn x86_64_microarch_level() -> &'static str {
+    unreachable!()
+}
+
+/// maps this host to the platform string used in PyPy's release filenames,
+/// e.g. `pypy3.10-v7.3.16-linux64.tar.bz2`
+fn pypy_platform() -> &'static str {
+    if cfg!(target_os = "macos") {
+        if cfg!(target_arch = "aarch64") {
+            "macos_arm64"
+        } else {
+            "macos_x86_64"
+        }
+    } else if cfg!(target_os = "linux") {
+        if cfg!(target_arch = "aarch64") {
+            "aarch64"
+        } else {
+            "linux64"
+        }
+    } else {
+        panic!("unsupported OS for pypy")
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct PyPyRelease {
+    version: String,
+    python_version: String,
+    files: Vec<PyPyFile>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct PyPyFile {
+    filename: String,
+    platform: String,
+}